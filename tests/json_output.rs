@@ -0,0 +1,69 @@
+use assert_cmd::Command;
+use serde_json::Value;
+
+fn run_json(args: &[&str]) -> (Option<i32>, Value) {
+    let output = Command::cargo_bin("is-test").unwrap().args(args).output().unwrap();
+    let json = serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON object");
+    (output.status.code(), json)
+}
+
+#[test]
+fn json_reports_passing_check() {
+    let (code, json) = run_json(&["--json", "string", "equal", "a", "a"]);
+    assert_eq!(code, Some(0));
+    assert_eq!(json["check"], "string.equal");
+    assert_eq!(json["passed"], true);
+    assert_eq!(json["inputs"]["string1"], "a");
+    assert_eq!(json["inputs"]["string2"], "a");
+}
+
+#[test]
+fn json_reports_failing_check() {
+    let (code, json) = run_json(&["int", "gt", "--json", "1", "5"]);
+    assert_eq!(code, Some(1));
+    assert_eq!(json["check"], "int.gt");
+    assert_eq!(json["passed"], false);
+    assert_eq!(json["detail"], "integer 1, which is not > 5");
+}
+
+#[test]
+fn json_reports_chain_as_one_object() {
+    let (code, json) = run_json(&["--json", "string", "empty", "x", "--or", "string", "empty", ""]);
+    assert_eq!(code, Some(0));
+    assert_eq!(json["chain"], "or");
+    assert_eq!(json["passed"], true);
+    assert_eq!(json["checks"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn stdin_lines_reports_failing_lines() {
+    let args = ["--stdin-lines", "string", "matches-regex", "-", "^[a-z]"];
+    let output = Command::cargo_bin("is-test").unwrap().args(args).write_stdin("alpha\nBeta\ngamma\n").output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Beta\n");
+
+    let any = Command::cargo_bin("is-test").unwrap().arg("--any").args(args).write_stdin("alpha\nBeta\n").output().unwrap();
+    assert_eq!(any.status.code(), Some(0));
+}
+
+#[test]
+fn stdin_lines_are_never_read_as_options() {
+    let args = ["--stdin-lines", "string", "matches-regex", "-", "^[a-z]+$"];
+    let output = Command::cargo_bin("is-test").unwrap().args(args).write_stdin("abc\n--help\n-x\n").output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "", "--help is a line, not a request for help");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "--help\n-x\n");
+
+    let args = ["--stdin-lines", "string", "field-count-eq", "-", ",", "2", "--collapse"];
+    let output = Command::cargo_bin("is-test").unwrap().args(args).write_stdin("a,b\n").output().unwrap();
+    assert_eq!(output.status.code(), Some(2), "options after the operand are rejected up front");
+}
+
+#[test]
+fn binary_contains_rejects_an_empty_pattern() {
+    for hex in ["", "0x", " "] {
+        let output = Command::cargo_bin("is-test").unwrap().args(["file", "binary-contains", "Cargo.toml", hex]).output().unwrap();
+        assert_eq!(output.status.code(), Some(2), "pattern {:?}", hex);
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "Hex byte pattern is empty\n");
+    }
+}
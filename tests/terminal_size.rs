@@ -0,0 +1,33 @@
+#![cfg(unix)]
+
+use assert_cmd::Command;
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+
+/// Runs `is system <args>` with its stdio on a fresh 80x24 pty and returns the exit code.
+fn run_under_pty(args: &[&str]) -> u32 {
+    let pair = native_pty_system()
+        .openpty(PtySize { rows: 24, cols: 80, pixel_width: 0, pixel_height: 0 })
+        .unwrap();
+    let mut command = CommandBuilder::new(env!("CARGO_BIN_EXE_is-test"));
+    command.arg("system");
+    command.args(args);
+    let mut child = pair.slave.spawn_command(command).unwrap();
+    drop(pair.slave);
+    child.wait().unwrap().exit_code()
+}
+
+#[test]
+fn term_size_reads_stdout_by_default() {
+    assert_eq!(run_under_pty(&["term-cols-ge", "80"]), 0);
+    assert_eq!(run_under_pty(&["term-cols-gt", "80"]), 1);
+    assert_eq!(run_under_pty(&["term-rows-eq", "24"]), 0);
+    assert_eq!(run_under_pty(&["term-rows-lt", "24"]), 1);
+    assert_eq!(run_under_pty(&["term-cols-eq", "80", "--fd", "0"]), 0);
+}
+
+#[test]
+fn term_size_exits_2_when_stdout_is_not_a_tty() {
+    let output = Command::cargo_bin("is-test").unwrap().args(["system", "term-cols-ge", "1"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "File descriptor 1 is not a terminal\n");
+}